@@ -0,0 +1,18 @@
+use crate::Length;
+
+/// Common interface for sentences that report water depth relative to some
+/// reference point (below the surface, below the transducer, relative to
+/// the transducer with a waterline/keel offset, ...), so callers can ask
+/// "how deep is it" without caring which depth sentence the device sent.
+pub trait Depth {
+    /// The reported water depth, if present.
+    fn depth(&self) -> Option<Length>;
+
+    /// The offset from the transducer to this sentence's depth reference
+    /// point, if it reports one. Positive is towards the waterline,
+    /// negative towards the keel. `None` for sentences, like DBS and DBT,
+    /// that don't carry an offset field.
+    fn offset(&self) -> Option<Length> {
+        None
+    }
+}
@@ -0,0 +1,107 @@
+//! Unit-safe representation of a physical length, shared by every
+//! depth/distance-bearing sentence (DBS, DBT, DPT, ...) so conversions
+//! live in one place and callers can never grab the wrong unit.
+
+use std::ops::{Add, Sub};
+
+/// Millimeters per foot (1 ft = 0.3048 m).
+const MM_PER_FOOT: f32 = 304.8;
+/// Millimeters per fathom (1 fathom = 6 ft).
+const MM_PER_FATHOM: f32 = 1_828.8;
+/// Millimeters per meter.
+const MM_PER_METER: f32 = 1_000.0;
+
+/// A length stored as a fixed-point integer number of millimeters.
+///
+/// Sentences often report the same physical quantity in feet, meters and
+/// fathoms at once; parsing into a single `Length` instead of one
+/// `Option<f32>` per unit avoids unit-confusion bugs and, by keeping the
+/// canonical value as an integer, avoids the rounding error that repeated
+/// float conversions between units would accumulate.
+///
+/// Construct with [`Length::from_meters`], [`Length::from_feet`] or
+/// [`Length::from_fathoms`] depending on which unit a sentence reported,
+/// then read back out in whichever unit the caller needs via
+/// [`Length::meters`], [`Length::feet`] or [`Length::fathoms`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length(i32);
+
+impl Length {
+    /// Constructs a `Length` from a value already expressed in millimeters.
+    pub const fn from_mm(mm: i32) -> Self {
+        Length(mm)
+    }
+
+    /// Constructs a `Length` from a value expressed in meters, rounding to
+    /// the nearest millimeter.
+    pub fn from_meters(meters: f32) -> Self {
+        Length((meters * MM_PER_METER).round() as i32)
+    }
+
+    /// Constructs a `Length` from a value expressed in feet, rounding to the
+    /// nearest millimeter.
+    pub fn from_feet(feet: f32) -> Self {
+        Length((feet * MM_PER_FOOT).round() as i32)
+    }
+
+    /// Constructs a `Length` from a value expressed in fathoms, rounding to
+    /// the nearest millimeter.
+    pub fn from_fathoms(fathoms: f32) -> Self {
+        Length((fathoms * MM_PER_FATHOM).round() as i32)
+    }
+
+    /// The underlying canonical value, in millimeters.
+    pub const fn mm(self) -> i32 {
+        self.0
+    }
+
+    /// The value in meters.
+    pub fn meters(self) -> f32 {
+        self.0 as f32 / MM_PER_METER
+    }
+
+    /// The value in feet.
+    pub fn feet(self) -> f32 {
+        self.0 as f32 / MM_PER_FOOT
+    }
+
+    /// The value in fathoms.
+    pub fn fathoms(self) -> f32 {
+        self.0 as f32 / MM_PER_FATHOM
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, rhs: Length) -> Length {
+        Length(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, rhs: Length) -> Length {
+        Length(self.0 - rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_across_units() {
+        let depth = Length::from_meters(2.4);
+        assert_eq!(depth.mm(), 2_400);
+        assert!((depth.feet() - 7.874_016).abs() < 1e-3);
+        assert!((depth.fathoms() - 1.312_336).abs() < 1e-3);
+    }
+
+    #[test]
+    fn feet_and_fathoms_round_to_the_nearest_millimeter() {
+        assert_eq!(Length::from_feet(1.0).mm(), 305);
+        assert_eq!(Length::from_fathoms(1.0).mm(), 1_829);
+    }
+}
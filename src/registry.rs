@@ -0,0 +1,105 @@
+//! Pluggable registry for proprietary / vendor-specific sentence parsers.
+//!
+//! The crate's built-in dispatch matches on [`SentenceType`], which only
+//! knows the standard sentence ids. Real marine gear frequently emits
+//! proprietary `P`-prefixed sentences (e.g. `$PGRME`) that have no
+//! `SentenceType` variant at all. `SentenceRegistry` lets downstream crates
+//! register a parser for any message id without forking this crate to
+//! extend `SentenceType`. Registration and dispatch are always keyed by the
+//! raw id string parsed from a sentence's header, never by `SentenceType`,
+//! so ids outside the built-in table are just as reachable as ids within it.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::{Error, NmeaSentence};
+
+/// A user-supplied parser for one message id, producing a boxed value of
+/// whatever type that id's sentence parses to.
+pub type CustomParser = Box<dyn Fn(NmeaSentence) -> Result<Box<dyn Any>, Error> + Send + Sync>;
+
+/// A registry of message id -> parser, consulted before falling back to
+/// the crate's built-in `SentenceType` dispatch table.
+#[derive(Default)]
+pub struct SentenceRegistry {
+    parsers: HashMap<String, CustomParser>,
+}
+
+impl SentenceRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        SentenceRegistry::default()
+    }
+
+    /// Registers `parser` for `message_id` (e.g. `"PGRME"`), replacing any
+    /// parser previously registered for the same id.
+    pub fn register<F>(&mut self, message_id: impl Into<String>, parser: F)
+    where
+        F: Fn(NmeaSentence) -> Result<Box<dyn Any>, Error> + Send + Sync + 'static,
+    {
+        self.parsers.insert(message_id.into(), Box::new(parser));
+    }
+
+    /// Removes any parser registered for `message_id`.
+    pub fn unregister(&mut self, message_id: &str) {
+        self.parsers.remove(message_id);
+    }
+
+    /// Looks up a registered parser for `message_id`, if any.
+    pub fn get(&self, message_id: &str) -> Option<&CustomParser> {
+        self.parsers.get(message_id)
+    }
+
+    /// Dispatches `sentence`, preferring a parser registered for
+    /// `message_id` over the built-in `SentenceType` dispatch.
+    ///
+    /// `message_id` must be the raw id string parsed from the sentence's
+    /// header (e.g. `"GGA"`, or a proprietary id like `"PGRME"` that has no
+    /// `SentenceType` variant at all) — pass it through from whatever step
+    /// in the header-parsing pipeline first extracts it, rather than
+    /// deriving it from `sentence.message_id`. `SentenceType` can only name
+    /// the ids it already knows about, so keying off it would make exactly
+    /// the custom/proprietary ids this registry exists for unreachable.
+    pub fn dispatch(&self, message_id: &str, sentence: NmeaSentence) -> Result<Box<dyn Any>, Error> {
+        match self.get(message_id) {
+            Some(parser) => parser(sentence),
+            None => crate::parse_sentence(sentence).map(|parsed| Box::new(parsed) as Box<dyn Any>),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SentenceType;
+
+    fn dummy_sentence() -> NmeaSentence<'static> {
+        NmeaSentence {
+            talker_id: "SD",
+            message_id: SentenceType::DBS,
+            data: "7.8,f,2.4,M,1.3,F",
+            checksum: 0x0,
+        }
+    }
+
+    #[test]
+    fn dispatches_a_custom_id_to_its_registered_parser() {
+        let mut registry = SentenceRegistry::new();
+        registry.register("PGRME", |_sentence| Ok(Box::new(42i32) as Box<dyn Any>));
+
+        let result = registry
+            .dispatch("PGRME", dummy_sentence())
+            .expect("registered parser should run");
+
+        assert_eq!(*result.downcast::<i32>().unwrap(), 42);
+    }
+
+    #[test]
+    fn falls_back_to_the_built_in_dispatch_for_an_unregistered_id() {
+        let registry = SentenceRegistry::new();
+
+        let result = registry.dispatch("DBS", dummy_sentence());
+
+        assert!(result.is_ok());
+    }
+}
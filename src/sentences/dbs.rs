@@ -1,6 +1,6 @@
-use nom::{character::complete::char, combinator::opt, number::complete::float};
+use nom::{character::complete::anychar, character::complete::char, combinator::opt, number::complete::float};
 
-use crate::{Error, NmeaSentence, SentenceType};
+use crate::{Depth, Error, Length, NmeaSentence, SentenceType};
 
 /// DBS - Depth Below Surface
 ///
@@ -8,7 +8,7 @@ use crate::{Error, NmeaSentence, SentenceType};
 /// ```text
 ///         1   2 3   4 5   6 7
 ///         |   | |   | |   | |
-///  $--DBT,x.x,f,x.x,M,x.x,F*hh<CR><LF>
+///  $--DBS,x.x,f,x.x,M,x.x,F*hh<CR><LF>
 /// Field Number:
 ///     1. Water depth, feet
 ///     2. f = feet
@@ -17,58 +17,156 @@ use crate::{Error, NmeaSentence, SentenceType};
 ///     5. Water depth, Fathoms
 ///     6. F = Fathoms
 ///     7. Checksum
-/// In real-world sensors, sometimes not all three conversions are reported. So you might see something like $SDDBT,,f,22.5,M,,F*cs
-/// Example: $SDDBT,7.8,f,2.4,M,1.3,F*0D
+/// In real-world sensors, sometimes not all three conversions are reported. So you might see something like $SDDBS,,f,22.5,M,,F*cs
+/// Example: $SDDBS,7.8,f,2.4,M,1.3,F*0A
 
 /// ```
 ///
 pub struct DbsData {
-    pub water_depth_feet: Option<f32>,
-    pub water_depth_meters: Option<f32>,
-    pub water_depth_fathoms: Option<f32>,
+    /// Water depth below the surface, independent of which unit(s) the
+    /// sentence happened to report it in. Picks the most precise reported
+    /// field, preferring meters, then fathoms, then feet.
+    pub depth: Option<Length>,
 }
 
+impl Depth for DbsData {
+    fn depth(&self) -> Option<Length> {
+        self.depth
+    }
+}
+
+/// Controls how strictly [`parse_dbs_with_options`] cross-checks a DBS
+/// sentence that reports the same depth in more than one unit.
+///
+/// The default (used by plain [`parse_dbs`]) does not cross-check the
+/// reported values against each other, since real-world sensors are known
+/// to report mutually inconsistent conversions. The `f`/`M`/`F` unit
+/// designators are still required in the default mode, but a wrong one is
+/// reported as a plain parse error rather than [`Error::UnexpectedUnit`].
+#[derive(Debug, Clone, Copy)]
+pub struct DbsParseOptions {
+    /// When `true`, requires the `f`/`M`/`F` unit designators to match
+    /// exactly (emitting [`Error::UnexpectedUnit`] otherwise) and, when two
+    /// or more of the three depth fields are present, checks they agree
+    /// with each other within `tolerance` (emitting
+    /// [`Error::InconsistentUnits`] otherwise).
+    pub validate: bool,
+    /// Relative tolerance allowed between the conversions of any two
+    /// reported fields, e.g. `0.005` for 0.5%. A floor of a few centimeters
+    /// is always applied on top of this so that tiny absolute depths don't
+    /// require unreasonable precision.
+    pub tolerance: f32,
+}
+
+impl Default for DbsParseOptions {
+    fn default() -> Self {
+        DbsParseOptions {
+            validate: false,
+            tolerance: 0.005,
+        }
+    }
+}
+
+/// Floor, in millimeters, below which two readings are always considered
+/// consistent regardless of `tolerance`.
+const MIN_CONSISTENCY_TOLERANCE_MM: f32 = 30.0;
+
 pub fn parse_dbs(sentence: NmeaSentence) -> Result<DbsData, Error> {
+    parse_dbs_with_options(sentence, DbsParseOptions::default())
+}
+
+/// Like [`parse_dbs`], but with [`DbsParseOptions`] controlling cross-unit
+/// validation of the sentence's redundant feet/meters/fathoms fields.
+pub fn parse_dbs_with_options(
+    sentence: NmeaSentence,
+    options: DbsParseOptions,
+) -> Result<DbsData, Error> {
     if sentence.message_id != SentenceType::DBS {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::DBS,
             found: sentence.message_id,
         })
     } else {
-        Ok(do_parse_dbs(sentence.data)?)
+        Ok(do_parse_dbs(sentence.data, options)?)
+    }
+}
+
+fn parse_unit(i: &str, expected: char, validate: bool) -> Result<(&str, char), Error> {
+    if validate {
+        let (i, found) = anychar(i)?;
+        if found != expected {
+            return Err(Error::UnexpectedUnit { expected, found });
+        }
+        Ok((i, found))
+    } else {
+        // Non-validating path: behave exactly like the original parser, which
+        // requires the expected designator and errors (via the `?` on a failed
+        // `char` parse) rather than silently consuming whatever byte is there.
+        Ok(char(expected)(i)?)
     }
 }
 
-fn do_parse_dbs(i: &str) -> Result<DbsData, Error> {
+fn check_consistent(
+    field: &'static str,
+    reference: Length,
+    value: Length,
+    tolerance: f32,
+) -> Result<(), Error> {
+    let diff_mm = (reference.mm() - value.mm()).unsigned_abs() as f32;
+    let allowed = (reference.mm().unsigned_abs() as f32 * tolerance).max(MIN_CONSISTENCY_TOLERANCE_MM);
+    if diff_mm > allowed {
+        return Err(Error::InconsistentUnits {
+            field,
+            expected: reference.meters(),
+            found: value.meters(),
+        });
+    }
+    Ok(())
+}
+
+fn do_parse_dbs(i: &str, options: DbsParseOptions) -> Result<DbsData, Error> {
     let (i, water_depth_feet) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, unit_feet) = char('f')(i)?;
-    // todo->should we check for unit_feet?
+    let (i, _unit_feet) = parse_unit(i, 'f', options.validate)?;
+    let (i, _) = char(',')(i)?;
 
     let (i, water_depth_meters) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, unit_meters) = char('M')(i)?;
-    // todo->should we check for unit_meters?
+    let (i, _unit_meters) = parse_unit(i, 'M', options.validate)?;
+    let (i, _) = char(',')(i)?;
 
     let (i, water_depth_fathoms) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, unit_fathoms) = char('F')(i)?;
-    // todo->should we check for unit_fathoms?
-
-    Ok(DbsData {
-        water_depth_feet,
-        water_depth_meters,
-        water_depth_fathoms,
-    })
+    let (_i, _unit_fathoms) = parse_unit(i, 'F', options.validate)?;
+
+    let feet = water_depth_feet.map(Length::from_feet);
+    let meters = water_depth_meters.map(Length::from_meters);
+    let fathoms = water_depth_fathoms.map(Length::from_fathoms);
+
+    let depth = meters.or(fathoms).or(feet);
+
+    if options.validate {
+        if let Some(reference) = depth {
+            if let Some(feet) = feet {
+                check_consistent("feet", reference, feet, options.tolerance)?;
+            }
+            if let Some(meters) = meters {
+                check_consistent("meters", reference, meters, options.tolerance)?;
+            }
+            if let Some(fathoms) = fathoms {
+                check_consistent("fathoms", reference, fathoms, options.tolerance)?;
+            }
+        }
+    }
+
+    Ok(DbsData { depth })
 }
 
 #[cfg(test)]
 mod test {
-    use approx::assert_relative_eq;
-
     use super::*;
-    use crate::{parse_nmea_sentence, SentenceType};
 
+    #[test]
     fn parse_dbs_with_nmea_sentence_struct() {
         let data = parse_dbs(NmeaSentence {
             talker_id: "SD",
@@ -77,5 +175,40 @@ mod test {
             checksum: 0x0,
         })
         .unwrap();
+        assert_eq!(data.depth, Some(Length::from_meters(2.4)));
+    }
+
+    #[test]
+    fn validation_rejects_inconsistent_units() {
+        let result = parse_dbs_with_options(
+            NmeaSentence {
+                talker_id: "SD",
+                message_id: SentenceType::DBS,
+                data: "7.8,f,99.0,M,1.3,F",
+                checksum: 0x0,
+            },
+            DbsParseOptions {
+                validate: true,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(Error::InconsistentUnits { .. })));
+    }
+
+    #[test]
+    fn validation_rejects_unexpected_unit() {
+        let result = parse_dbs_with_options(
+            NmeaSentence {
+                talker_id: "SD",
+                message_id: SentenceType::DBS,
+                data: "7.8,x,2.4,M,1.3,F",
+                checksum: 0x0,
+            },
+            DbsParseOptions {
+                validate: true,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(Error::UnexpectedUnit { .. })));
     }
 }
@@ -0,0 +1,86 @@
+use nom::{character::complete::char, combinator::opt, number::complete::float};
+
+use crate::{Depth, Error, Length, NmeaSentence, SentenceType};
+
+/// DBT - Depth Below Transducer
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dbt_depth_below_transducer>
+/// ```text
+///         1   2 3   4 5   6 7
+///         |   | |   | |   | |
+///  $--DBT,x.x,f,x.x,M,x.x,F*hh<CR><LF>
+/// Field Number:
+///     1. Water depth, feet
+///     2. f = feet
+///     3. Water depth, meters
+///     4. M = meters
+///     5. Water depth, Fathoms
+///     6. F = Fathoms
+///     7. Checksum
+/// In real-world sensors, sometimes not all three conversions are reported. So you might see something like $SDDBT,,f,22.5,M,,F*cs
+/// Example: $SDDBT,7.8,f,2.4,M,1.3,F*0D
+/// ```
+///
+pub struct DbtData {
+    /// Water depth below the transducer, independent of which unit(s) the
+    /// sentence happened to report it in. Picks the most precise reported
+    /// field, preferring meters, then fathoms, then feet.
+    pub depth: Option<Length>,
+}
+
+impl Depth for DbtData {
+    fn depth(&self) -> Option<Length> {
+        self.depth
+    }
+}
+
+pub fn parse_dbt(sentence: NmeaSentence) -> Result<DbtData, Error> {
+    if sentence.message_id != SentenceType::DBT {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::DBT,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dbt(sentence.data)?)
+    }
+}
+
+fn do_parse_dbt(i: &str) -> Result<DbtData, Error> {
+    let (i, water_depth_feet) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _unit_feet) = char('f')(i)?;
+    let (i, _) = char(',')(i)?;
+
+    let (i, water_depth_meters) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _unit_meters) = char('M')(i)?;
+    let (i, _) = char(',')(i)?;
+
+    let (i, water_depth_fathoms) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (_i, _unit_fathoms) = char('F')(i)?;
+
+    let depth = water_depth_meters
+        .map(Length::from_meters)
+        .or_else(|| water_depth_fathoms.map(Length::from_fathoms))
+        .or_else(|| water_depth_feet.map(Length::from_feet));
+
+    Ok(DbtData { depth })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_dbt_with_nmea_sentence_struct() {
+        let data = parse_dbt(NmeaSentence {
+            talker_id: "SD",
+            message_id: SentenceType::DBT,
+            data: "7.8,f,2.4,M,1.3,F",
+            checksum: 0x0,
+        })
+        .unwrap();
+        assert_eq!(data.depth(), Some(Length::from_meters(2.4)));
+    }
+}
@@ -0,0 +1,83 @@
+use nom::{character::complete::char, combinator::opt, number::complete::float, sequence::preceded};
+
+use crate::{Depth, Error, Length, NmeaSentence, SentenceType};
+
+/// DPT - Depth of Water
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dpt_depth_of_water>
+/// ```text
+///         1   2   3
+///         |   |   |
+///  $--DPT,x.x,x.x,x.x*hh<CR><LF>
+/// Field Number:
+///     1. Water depth relative to the transducer, meters
+///     2. Offset from transducer, meters
+///        (positive means distance from transducer to water line,
+///        negative means distance from transducer to keel)
+///     3. Maximum range scale in use, meters (optional)
+///     4. Checksum
+/// Example: $SDDPT,2.4,0.5*54
+/// ```
+///
+pub struct DptData {
+    /// Water depth relative to the transducer.
+    pub depth: Option<Length>,
+    /// Offset from the transducer to the sentence's reference point:
+    /// positive towards the waterline, negative towards the keel.
+    pub offset: Option<Length>,
+    /// Maximum depth the instrument can currently range to, if reported.
+    pub max_range: Option<Length>,
+}
+
+impl Depth for DptData {
+    fn depth(&self) -> Option<Length> {
+        self.depth
+    }
+
+    fn offset(&self) -> Option<Length> {
+        self.offset
+    }
+}
+
+pub fn parse_dpt(sentence: NmeaSentence) -> Result<DptData, Error> {
+    if sentence.message_id != SentenceType::DPT {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::DPT,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dpt(sentence.data)?)
+    }
+}
+
+fn do_parse_dpt(i: &str) -> Result<DptData, Error> {
+    let (i, depth) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, offset) = opt(float)(i)?;
+    let (_i, max_range) = opt(preceded(char(','), float))(i)?;
+
+    Ok(DptData {
+        depth: depth.map(Length::from_meters),
+        offset: offset.map(Length::from_meters),
+        max_range: max_range.map(Length::from_meters),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_dpt_with_nmea_sentence_struct() {
+        let data = parse_dpt(NmeaSentence {
+            talker_id: "SD",
+            message_id: SentenceType::DPT,
+            data: "2.4,0.5",
+            checksum: 0x0,
+        })
+        .unwrap();
+        assert_eq!(data.depth(), Some(Length::from_meters(2.4)));
+        assert_eq!(data.offset(), Some(Length::from_meters(0.5)));
+        assert_eq!(data.max_range, None);
+    }
+}
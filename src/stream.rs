@@ -0,0 +1,223 @@
+//! Streaming front-end for callers that receive NMEA data byte-by-byte off
+//! a serial port, rather than as pre-framed, pre-buffered lines.
+//!
+//! This is a hand-rolled byte framer rather than one built directly on
+//! nom's incomplete-input (`Err::Incomplete`) support: `NmeaSentence`
+//! parsing in this crate works over a complete `&str` line, so there is no
+//! streaming nom parser to drive directly, and the framing problem here
+//! (find `$`/`!`...`<CR><LF>`, retain the tail, resync on garbage) is
+//! simple enough as byte-slice scanning that routing it through nom
+//! wouldn't simplify it. If a streaming nom parser for whole sentences is
+//! added later, this module should be rebuilt on top of it instead.
+
+use crate::{parse_nmea_sentence, Error, NmeaSentence};
+
+/// NMEA 0183 caps a sentence at 82 characters on the wire, including the
+/// leading `$`/`!`, the trailing `*hh` checksum and the `<CR><LF>`
+/// terminator.
+const MAX_SENTENCE_LEN: usize = 82;
+
+/// A framing problem detected while resynchronizing the stream, as opposed
+/// to a well-framed sentence that failed to parse (see [`StreamError`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FramingError {
+    /// A line exceeded the 82-character NMEA 0183 limit, counting the
+    /// leading `$`/`!` and the trailing `<CR><LF>`.
+    SentenceTooLong,
+    /// A `<CR><LF>`-terminated line had no well-formed `*hh` checksum
+    /// suffix (a `*` followed by exactly two hex digits), or was not valid
+    /// UTF-8.
+    MissingChecksum,
+    /// A line had a `*hh` suffix, but `hh` did not match the XOR checksum
+    /// of the bytes between the leading `$`/`!` and the `*`.
+    ChecksumMismatch,
+}
+
+/// Everything that [`StreamParser::push`] can report for one framed line:
+/// either a framing problem, or a framing- and checksum-correct line that
+/// [`parse_nmea_sentence`] still rejected.
+#[derive(Debug)]
+pub enum StreamError {
+    /// The line could not even be framed as a sentence, or failed the
+    /// checksum check.
+    Framing(FramingError),
+    /// The line was framed correctly and its checksum matched, but
+    /// [`parse_nmea_sentence`] rejected it anyway, e.g. an unrecognized
+    /// sentence id.
+    Parse(Error),
+}
+
+/// Incrementally frames and checksum-validates NMEA sentences out of a raw
+/// byte stream, before handing each one to [`parse_nmea_sentence`].
+///
+/// Feed it arbitrarily-sized chunks with [`StreamParser::push`]; it buffers
+/// the tail of a partial sentence across calls and returns every sentence
+/// (or error) that became complete as a result of the new bytes. A corrupt
+/// or overlong line is discarded and the parser resynchronizes on the next
+/// `$`/`!` start delimiter, so one bad line doesn't take down the rest of
+/// the stream.
+#[derive(Debug, Default)]
+pub struct StreamParser {
+    buf: Vec<u8>,
+    /// Raw, newline-stripped lines framed by the most recent `push`, kept
+    /// alive on `self` so the `NmeaSentence`s returned from `push` can
+    /// borrow from them.
+    lines: Vec<Result<String, FramingError>>,
+}
+
+impl StreamParser {
+    /// Creates an empty stream parser.
+    pub fn new() -> Self {
+        StreamParser::default()
+    }
+
+    /// Feeds `chunk` into the parser, returning every sentence (or error)
+    /// that became complete as a result, in the order they were framed.
+    /// Bytes belonging to a still-incomplete trailing sentence are kept
+    /// for the next call.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Result<NmeaSentence<'_>, StreamError>> {
+        self.buf.extend_from_slice(chunk);
+        self.lines.clear();
+
+        loop {
+            let Some(start) = self.buf.iter().position(|&b| b == b'$' || b == b'!') else {
+                self.buf.clear();
+                break;
+            };
+            self.buf.drain(..start);
+
+            let Some(newline) = self.buf.iter().position(|&b| b == b'\n') else {
+                if self.buf.len() > MAX_SENTENCE_LEN {
+                    self.lines.push(Err(FramingError::SentenceTooLong));
+                    self.buf.drain(..1);
+                    continue;
+                }
+                break;
+            };
+
+            let raw: Vec<u8> = self.buf.drain(..=newline).collect();
+
+            if raw.len() > MAX_SENTENCE_LEN {
+                self.lines.push(Err(FramingError::SentenceTooLong));
+                continue;
+            }
+
+            let trimmed = raw
+                .strip_suffix(b"\r\n")
+                .or_else(|| raw.strip_suffix(b"\n"))
+                .unwrap_or(&raw);
+
+            match std::str::from_utf8(trimmed) {
+                Ok(line) => match verify_checksum(line) {
+                    Ok(()) => self.lines.push(Ok(line.to_owned())),
+                    Err(framing_err) => self.lines.push(Err(framing_err)),
+                },
+                Err(_) => self.lines.push(Err(FramingError::MissingChecksum)),
+            }
+        }
+
+        self.lines
+            .iter()
+            .map(|line| match line {
+                Ok(line) => parse_nmea_sentence(line).map_err(StreamError::Parse),
+                Err(framing_err) => Err(StreamError::Framing(framing_err.clone())),
+            })
+            .collect()
+    }
+}
+
+/// Checks that `line` (the `$`/`!`-prefixed sentence body, with the
+/// `<CR><LF>` terminator already stripped) carries a `*hh` suffix whose hex
+/// value matches the XOR of every byte between the leading delimiter and
+/// the `*`.
+fn verify_checksum(line: &str) -> Result<(), FramingError> {
+    let body = &line[1..];
+    let (data, checksum_hex) = body.split_once('*').ok_or(FramingError::MissingChecksum)?;
+    if checksum_hex.len() != 2 {
+        return Err(FramingError::MissingChecksum);
+    }
+    let expected =
+        u8::from_str_radix(checksum_hex, 16).map_err(|_| FramingError::MissingChecksum)?;
+    let computed = data.bytes().fold(0u8, |acc, b| acc ^ b);
+    if computed != expected {
+        return Err(FramingError::ChecksumMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VALID_DBT: &[u8] = b"$SDDBT,7.8,f,2.4,M,1.3,F*0D\r\n";
+
+    #[test]
+    fn parses_a_complete_sentence_in_one_push() {
+        let mut parser = StreamParser::new();
+        let results = parser.push(VALID_DBT);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+
+    #[test]
+    fn parses_a_sentence_split_across_two_pushes() {
+        let mut parser = StreamParser::new();
+        let (first, second) = VALID_DBT.split_at(15);
+
+        assert!(parser.push(first).is_empty());
+
+        let results = parser.push(second);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+
+    #[test]
+    fn resyncs_past_a_garbage_prefix() {
+        let mut parser = StreamParser::new();
+        let mut chunk = b"garbage before any delimiter".to_vec();
+        chunk.extend_from_slice(VALID_DBT);
+
+        let results = parser.push(&chunk);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+
+    #[test]
+    fn overlong_line_is_flagged_but_does_not_swallow_the_next_sentence() {
+        let mut parser = StreamParser::new();
+        let mut chunk = b"$".to_vec();
+        chunk.extend(std::iter::repeat(b'A').take(90));
+        chunk.extend_from_slice(b"*00\r\n");
+        chunk.extend_from_slice(VALID_DBT);
+
+        let results = parser.push(&chunk);
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            results[0],
+            Err(StreamError::Framing(FramingError::SentenceTooLong))
+        ));
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn line_without_checksum_delimiter_is_flagged() {
+        let mut parser = StreamParser::new();
+        let results = parser.push(b"$SDDBT,7.8,f,2.4,M,1.3,F\r\n");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(StreamError::Framing(FramingError::MissingChecksum))
+        ));
+    }
+
+    #[test]
+    fn line_with_wrong_checksum_is_flagged() {
+        let mut parser = StreamParser::new();
+        let results = parser.push(b"$SDDBT,7.8,f,2.4,M,1.3,F*FF\r\n");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(StreamError::Framing(FramingError::ChecksumMismatch))
+        ));
+    }
+}